@@ -1,9 +1,11 @@
 use std::env;
-use std::net::{Ipv4Addr, AddrParseError};
+use std::net::{AddrParseError, IpAddr, Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 use std::num::ParseIntError;
 use std::fmt;
 
+use serde::Serialize;
+
 // Define a custom error type for network parsing errors
 #[derive(Debug)]
 enum NetworkParseError {
@@ -41,34 +43,41 @@ fn main() {
     // Collect command-line arguments
     let args: Vec<String> = env::args().collect();
 
+    if let Some(split_pos) = args.iter().position(|a| a == "--split") {
+        if let Err(e) = run_split(&args, split_pos) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    if let Some(aggregate_pos) = args.iter().position(|a| a == "--aggregate") {
+        if let Err(e) = run_aggregate(&args, aggregate_pos) {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+        return;
+    }
+
+    let json_mode = args.iter().any(|a| a == "--json");
+    let args: Vec<String> = args.into_iter().filter(|a| a != "--json").collect();
+
     // Ensure the correct number of arguments is provided
     if args.len() != 2 && args.len() != 3 {
-        eprintln!("Usage: {} <network/mask or network mask>", args[0]);
+        eprintln!("Usage: {} [--json] <network/mask or network mask>", args[0]);
+        eprintln!("       {} <network/mask> --split <new prefix>", args[0]);
+        eprintln!("       {} --aggregate <network/mask>...", args[0]);
         std::process::exit(1);
     }
 
     // Parse arguments and handle errors
     match parse_args(&args) {
         Ok((network, mask)) => {
-            let network_address = Ipv4Addr::from(u32::from(network) & u32::from(mask));
-            let broadcast_address = Ipv4Addr::from(u32::from(network) | !u32::from(mask));
-            let first_address = if mask_to_prefix_length(mask) == 32 { network_address } else { Ipv4Addr::from(u32::from(network_address) + 1) };
-            let last_address = if mask_to_prefix_length(mask) == 32 { network_address } else { Ipv4Addr::from(u32::from(broadcast_address) - 1) };
-            let wildcard_mask = Ipv4Addr::from(!u32::from(mask));
-            let host_count = if mask_to_prefix_length(mask) == 32 { 1 } else { (u32::from(broadcast_address) - u32::from(network_address) - 1) as usize };
-            let network_class = get_network_class(network);
-            let prefix_length = mask_to_prefix_length(mask);
-
-            // Print out the network details
-            println!("\x1b[0m{:<10} {:<30} {}", "Address:", network, to_colored_binary_string(network, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "Netmask:", format!("{} = {}", mask, prefix_length), to_colored_binary_string(mask, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "Wildcard:", wildcard_mask, to_colored_binary_string(wildcard_mask, prefix_length));
-            println!("=>");
-            println!("\x1b[0m{:<10} {:<30} {}", "Network:", format!("{}/{}", network_address, prefix_length), to_colored_binary_string(network_address, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "HostMin:", first_address, to_colored_binary_string(first_address, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "HostMax:", last_address, to_colored_binary_string(last_address, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "Broadcast:", broadcast_address, to_colored_binary_string(broadcast_address, prefix_length));
-            println!("\x1b[0m{:<10} {:<30} {}", "Hosts/Net:", host_count, format!("Class {}, {}", network_class, get_network_type(network)));
+            if json_mode {
+                print_network_info_json(network, mask);
+            } else {
+                print_network_info(network, mask);
+            }
         }
         Err(e) => {
             eprintln!("Error: {}", e);
@@ -77,56 +86,358 @@ fn main() {
     }
 }
 
+// Deaggregate a network into equal child subnets at a longer prefix length
+fn run_split(args: &[String], split_pos: usize) -> Result<(), NetworkParseError> {
+    if split_pos != 2 || args.len() != 4 {
+        return Err(NetworkParseError::InvalidFormat);
+    }
+
+    let (network, mask) = parse_args(&args[..2])?;
+    let new_prefix_str = args[3].strip_prefix('/').unwrap_or(&args[3]);
+    let new_prefix = new_prefix_str.parse::<u32>()?;
+
+    for (child_network, child_mask) in split_children(network, mask, new_prefix)? {
+        print_network_info(child_network, child_mask);
+    }
+
+    Ok(())
+}
+
+// The child subnets of (network, mask) at new_prefix, in order
+fn split_children(network: IpAddr, mask: IpAddr, new_prefix: u32) -> Result<Vec<(IpAddr, IpAddr)>, NetworkParseError> {
+    let parent_prefix = mask_to_prefix_length(mask);
+    if new_prefix <= parent_prefix || new_prefix > max_prefix_length(network) {
+        return Err(NetworkParseError::InvalidMask);
+    }
+
+    let (parent_network_bits, parent_last_bits) = network_bounds(network, mask);
+    let child_mask = mask_from_prefix(network, new_prefix);
+    let block_size = 1u128 << (max_prefix_length(network) - new_prefix);
+
+    let mut children = Vec::new();
+    let mut base = parent_network_bits;
+    loop {
+        children.push((bits_to_addr(base, network), child_mask));
+        match base.checked_add(block_size) {
+            Some(next) if next <= parent_last_bits => base = next,
+            _ => break,
+        }
+    }
+
+    Ok(children)
+}
+
+// Collapse several networks into the smallest single prefix that contains them all
+fn run_aggregate(args: &[String], aggregate_pos: usize) -> Result<(), NetworkParseError> {
+    if aggregate_pos != 1 || args.len() < 4 {
+        return Err(NetworkParseError::InvalidFormat);
+    }
+
+    let networks = args[2..]
+        .iter()
+        .map(|s| parse_cidr(s))
+        .collect::<Result<Vec<(IpAddr, IpAddr)>, NetworkParseError>>()?;
+
+    let (network, mask, is_exact) = aggregate_networks(&networks)?;
+    print_network_info(network, mask);
+
+    if !is_exact {
+        eprintln!(
+            "Note: inputs are not exactly summarizable; the aggregate also covers unrelated address space"
+        );
+    }
+
+    Ok(())
+}
+
+// The smallest (network, mask) covering every input, plus whether the inputs together cover all of it with no gaps
+fn aggregate_networks(networks: &[(IpAddr, IpAddr)]) -> Result<(IpAddr, IpAddr, bool), NetworkParseError> {
+    if networks.is_empty() {
+        return Err(NetworkParseError::InvalidFormat);
+    }
+
+    let family = networks[0].0;
+    let max_prefix = max_prefix_length(family);
+    if networks.iter().any(|(n, _)| max_prefix_length(*n) != max_prefix) {
+        // Mixing address families has no single covering prefix
+        return Err(NetworkParseError::InvalidFormat);
+    }
+
+    let mut bounds: Vec<(u128, u128)> = networks.iter().map(|&(n, m)| network_bounds(n, m)).collect();
+    bounds.sort_by_key(|&(base, _)| base);
+    let min_base = bounds.first().unwrap().0;
+    let max_last = bounds.iter().map(|&(_, last)| last).max().unwrap();
+    // The inputs cover their span with no gaps only if each one starts at or before the address
+    // just past everything the earlier ones cover; overlapping or nested inputs are fine
+    let mut covered_last = bounds[0].1;
+    let mut is_contiguous = true;
+    for &(base, last) in &bounds[1..] {
+        if base > covered_last.saturating_add(1) {
+            is_contiguous = false;
+        }
+        covered_last = covered_last.max(last);
+    }
+
+    // Walk prefixes from the narrowest down until both endpoints fall under the same mask
+    let aggregate_prefix = (0..=max_prefix)
+        .rev()
+        .find(|&p| {
+            let mask_bits = addr_to_bits(mask_from_prefix(family, p));
+            min_base & mask_bits == max_last & mask_bits
+        })
+        .unwrap_or(0);
+
+    let aggregate_mask = mask_from_prefix(family, aggregate_prefix);
+    let (aggregate_base, aggregate_last) = network_bounds(bits_to_addr(min_base, family), aggregate_mask);
+    let is_exact = is_contiguous && aggregate_base == min_base && aggregate_last == max_last;
+
+    Ok((bits_to_addr(aggregate_base, family), aggregate_mask, is_exact))
+}
+
+// The masked network address and the all-ones last address of a network, as bit patterns
+fn network_bounds(network: IpAddr, mask: IpAddr) -> (u128, u128) {
+    let network_bits = addr_to_bits(network) & addr_to_bits(mask);
+    let host_mask = width_mask(max_prefix_length(network)) & !addr_to_bits(mask);
+    (network_bits, network_bits | host_mask)
+}
+
+// Every value derivable from a (network, mask) pair, shared by the colored table and the JSON output
+struct NetworkCalc {
+    network: IpAddr,
+    mask: IpAddr,
+    prefix_length: u32,
+    network_address: IpAddr,
+    wildcard_mask: IpAddr,
+    first_address: IpAddr,
+    last_address: IpAddr,
+    usable_last_address: IpAddr,
+    host_count: u128,
+}
+
+// Compute the full network breakdown for either address family
+fn compute_network(network: IpAddr, mask: IpAddr) -> NetworkCalc {
+    let prefix_length = mask_to_prefix_length(mask);
+    let max_prefix = max_prefix_length(network);
+
+    let (network_bits, last_bits) = network_bounds(network, mask);
+    let network_address = bits_to_addr(network_bits, network);
+    let last_address = bits_to_addr(last_bits, network);
+    let wildcard_mask = bits_to_addr(width_mask(max_prefix) & !addr_to_bits(mask), network);
+
+    let is_host_route = prefix_length == max_prefix;
+    let first_address = if is_host_route { network_address } else { bits_to_addr(network_bits + 1, network) };
+    let usable_last_address = if is_host_route { last_address } else { bits_to_addr(last_bits - 1, network) };
+    let host_count: u128 = if is_host_route { 1 } else { last_bits - network_bits - 1 };
+
+    NetworkCalc {
+        network,
+        mask,
+        prefix_length,
+        network_address,
+        wildcard_mask,
+        first_address,
+        last_address,
+        usable_last_address,
+        host_count,
+    }
+}
+
+// Print the colored, human-readable network breakdown
+fn print_network_info(network: IpAddr, mask: IpAddr) {
+    let calc = compute_network(network, mask);
+    let prefix_length = calc.prefix_length;
+
+    println!("\x1b[0m{:<10} {:<30} {}", "Address:", calc.network, to_colored_binary_string(calc.network, prefix_length));
+    println!("\x1b[0m{:<10} {:<30} {}", "Netmask:", format!("{} = {}", calc.mask, prefix_length), to_colored_binary_string(calc.mask, prefix_length));
+    println!("\x1b[0m{:<10} {:<30} {}", "Wildcard:", calc.wildcard_mask, to_colored_binary_string(calc.wildcard_mask, prefix_length));
+    println!("=>");
+    println!("\x1b[0m{:<10} {:<30} {}", "Network:", format!("{}/{}", calc.network_address, prefix_length), to_colored_binary_string(calc.network_address, prefix_length));
+    println!("\x1b[0m{:<10} {:<30} {}", "HostMin:", calc.first_address, to_colored_binary_string(calc.first_address, prefix_length));
+    println!("\x1b[0m{:<10} {:<30} {}", "HostMax:", calc.usable_last_address, to_colored_binary_string(calc.usable_last_address, prefix_length));
+
+    match calc.network {
+        IpAddr::V4(v4) => {
+            println!("\x1b[0m{:<10} {:<30} {}", "Broadcast:", calc.last_address, to_colored_binary_string(calc.last_address, prefix_length));
+            let class_and_type = format!("Class {}, {}", get_network_class(v4), get_network_type(v4));
+            println!("\x1b[0m{:<10} {:<30} {}", "Hosts/Net:", calc.host_count, class_and_type);
+        }
+        IpAddr::V6(v6) => {
+            // v6 has no broadcast; the all-ones host address is just the last address in the block
+            println!("\x1b[0m{:<10} {:<30} {}", "LastAddr:", calc.last_address, to_colored_binary_string(calc.last_address, prefix_length));
+            println!("\x1b[0m{:<10} {:<30} {}", "Hosts/Net:", calc.host_count, get_v6_scope(v6));
+        }
+    }
+}
+
+// Machine-readable counterpart to `print_network_info`: same values, serialized as JSON
+fn print_network_info_json(network: IpAddr, mask: IpAddr) {
+    let info = NetworkInfo::from(compute_network(network, mask));
+    match serde_json::to_string_pretty(&info) {
+        Ok(json) => println!("{}", json),
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            std::process::exit(1);
+        }
+    }
+}
+
+// JSON-serializable view of a `NetworkCalc`; `class` and `broadcast` are v4-only
+#[derive(Serialize)]
+struct NetworkInfo {
+    address: String,
+    netmask: String,
+    prefix: u32,
+    wildcard: String,
+    network: String,
+    hostmin: String,
+    hostmax: String,
+    broadcast: Option<String>,
+    #[serde(rename = "hosts_net")]
+    hosts_net: u128,
+    class: Option<char>,
+    #[serde(rename = "type")]
+    network_type: String,
+}
+
+impl From<NetworkCalc> for NetworkInfo {
+    fn from(calc: NetworkCalc) -> NetworkInfo {
+        let (broadcast, class, network_type) = match calc.network {
+            IpAddr::V4(v4) => (
+                Some(calc.last_address.to_string()),
+                Some(get_network_class(v4)),
+                get_network_type(v4).to_string(),
+            ),
+            IpAddr::V6(v6) => (None, None, get_v6_scope(v6).to_string()),
+        };
+
+        NetworkInfo {
+            address: calc.network.to_string(),
+            netmask: calc.mask.to_string(),
+            prefix: calc.prefix_length,
+            wildcard: calc.wildcard_mask.to_string(),
+            network: calc.network_address.to_string(),
+            hostmin: calc.first_address.to_string(),
+            hostmax: calc.usable_last_address.to_string(),
+            broadcast,
+            hosts_net: calc.host_count,
+            class,
+            network_type,
+        }
+    }
+}
+
 // Parse command-line arguments
-fn parse_args(args: &[String]) -> Result<(Ipv4Addr, Ipv4Addr), NetworkParseError> {
+fn parse_args(args: &[String]) -> Result<(IpAddr, IpAddr), NetworkParseError> {
     if args.len() == 2 {
         let input = &args[1];
         if input.contains('/') {
-            let parts: Vec<&str> = input.split('/').collect();
-            if parts.len() != 2 {
-                return Err(NetworkParseError::InvalidFormat);
-            }
-            let network = Ipv4Addr::from_str(parts[0])?;
-            let prefix_length = parts[1].parse::<u32>()?;
-            if prefix_length > 32 {
-                return Err(NetworkParseError::InvalidMask);
-            }
-            let mask = !0u32 << (32 - prefix_length);
-            return Ok((network, Ipv4Addr::from(mask)));
+            parse_cidr(input)
         } else {
-            return Err(NetworkParseError::InvalidFormat);
+            Err(NetworkParseError::InvalidFormat)
         }
     } else if args.len() == 3 {
-        let network = Ipv4Addr::from_str(&args[1])?;
-        let mask = parse_mask(&args[2])?;
-        return Ok((network, mask));
+        let network = IpAddr::from_str(&args[1])?;
+        let mask = parse_mask(network, &args[2])?;
+        Ok((network, mask))
     } else {
         Err(NetworkParseError::InvalidFormat)
     }
 }
 
-// Parse the subnet mask, either in dotted decimal or prefix length format
-fn parse_mask(mask: &str) -> Result<Ipv4Addr, NetworkParseError> {
-    if mask.contains('.') {
-        Ipv4Addr::from_str(mask).map_err(NetworkParseError::from)
+// Parse a single "network/prefix" or "network/255.255.255.0" token into (network, mask)
+fn parse_cidr(input: &str) -> Result<(IpAddr, IpAddr), NetworkParseError> {
+    let parts: Vec<&str> = input.split('/').collect();
+    if parts.len() != 2 {
+        return Err(NetworkParseError::InvalidFormat);
+    }
+    let network = IpAddr::from_str(parts[0])?;
+    if parts[1].contains('.') {
+        // network/255.255.255.0: route through the dotted-mask path, not prefix-length parsing
+        return Ok((network, parse_mask(network, parts[1])?));
+    }
+    let prefix_length = parts[1].parse::<u32>()?;
+    if prefix_length > max_prefix_length(network) {
+        return Err(NetworkParseError::InvalidMask);
+    }
+    Ok((network, mask_from_prefix(network, prefix_length)))
+}
+
+// Parse the subnet mask for a given address family, either in dotted decimal (v4 only) or prefix length format
+fn parse_mask(family: IpAddr, mask: &str) -> Result<IpAddr, NetworkParseError> {
+    if family.is_ipv4() && mask.contains('.') {
+        let m = Ipv4Addr::from_str(mask)?;
+        if !is_contiguous_mask(u32::from(m)) {
+            return Err(NetworkParseError::InvalidMask);
+        }
+        Ok(IpAddr::V4(m))
     } else {
         let prefix_length = mask.parse::<u32>()?;
-        if prefix_length > 32 {
+        if prefix_length > max_prefix_length(family) {
             return Err(NetworkParseError::InvalidMask);
         }
-        let mask = !0u32 << (32 - prefix_length);
-        Ok(Ipv4Addr::from(mask))
+        Ok(mask_from_prefix(family, prefix_length))
     }
 }
 
+// A dotted-decimal mask is valid only if its one-bits form an unbroken run from the MSB:
+// inverting it then adding one must carry all the way through the low run of ones, leaving zero
+fn is_contiguous_mask(mask: u32) -> bool {
+    let inverted = !mask;
+    inverted.wrapping_add(1) & inverted == 0
+}
+
+// The widest prefix length for an address's family: 32 for v4, 128 for v6
+fn max_prefix_length(addr: IpAddr) -> u32 {
+    match addr {
+        IpAddr::V4(_) => 32,
+        IpAddr::V6(_) => 128,
+    }
+}
+
+// Build the netmask address for a prefix length, matching the family of `family`
+fn mask_from_prefix(family: IpAddr, prefix_length: u32) -> IpAddr {
+    match family {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(if prefix_length == 0 { 0 } else { !0u32 << (32 - prefix_length) })),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(if prefix_length == 0 { 0 } else { !0u128 << (128 - prefix_length) })),
+    }
+}
+
+// Convert an address to its bit pattern, right-aligned in a u128
+fn addr_to_bits(addr: IpAddr) -> u128 {
+    match addr {
+        IpAddr::V4(a) => u32::from(a) as u128,
+        IpAddr::V6(a) => u128::from(a),
+    }
+}
+
+// Rebuild an address from a bit pattern, matching the family of `family`
+fn bits_to_addr(bits: u128, family: IpAddr) -> IpAddr {
+    match family {
+        IpAddr::V4(_) => IpAddr::V4(Ipv4Addr::from(bits as u32)),
+        IpAddr::V6(_) => IpAddr::V6(Ipv6Addr::from(bits)),
+    }
+}
+
+// An all-ones bit pattern `width` bits wide, right-aligned in a u128
+fn width_mask(width: u32) -> u128 {
+    if width >= 128 { u128::MAX } else { (1u128 << width) - 1 }
+}
+
 // Convert a subnet mask to its prefix length
-fn mask_to_prefix_length(mask: Ipv4Addr) -> u32 {
-    u32::from(mask).count_ones()
+fn mask_to_prefix_length(mask: IpAddr) -> u32 {
+    addr_to_bits(mask).count_ones()
 }
 
-// Convert an IP address to a colored binary string with correct dot placement
-fn to_colored_binary_string(addr: Ipv4Addr, prefix_length: u32) -> String {
-    // Create the binary string without dots initially
+// Convert an IP address to a colored binary string with correct separator placement
+fn to_colored_binary_string(addr: IpAddr, prefix_length: u32) -> String {
+    match addr {
+        IpAddr::V4(a) => to_colored_binary_string_v4(a, prefix_length),
+        IpAddr::V6(a) => to_colored_binary_string_v6(a, prefix_length),
+    }
+}
+
+// v4 variant: groups the binary string into 8-bit octets
+fn to_colored_binary_string_v4(addr: Ipv4Addr, prefix_length: u32) -> String {
     let binary_string = format!(
         "{:08b}{:08b}{:08b}{:08b}",
         addr.octets()[0],
@@ -134,12 +445,23 @@ fn to_colored_binary_string(addr: Ipv4Addr, prefix_length: u32) -> String {
         addr.octets()[2],
         addr.octets()[3]
     );
+    let binary_string_with_dots = add_separators(&binary_string, 8);
+    colorize_binary(&binary_string_with_dots, prefix_length, 8)
+}
 
-    // Ensure proper dot placement
-    let binary_string_with_dots = add_dots(&binary_string);
+// v6 variant: groups the binary string into 16-bit hextets
+fn to_colored_binary_string_v6(addr: Ipv6Addr, prefix_length: u32) -> String {
+    let binary_string: String = addr.segments().iter().map(|seg| format!("{:016b}", seg)).collect();
+    let binary_string_with_dots = add_separators(&binary_string, 16);
+    colorize_binary(&binary_string_with_dots, prefix_length, 16)
+}
 
-    // Convert prefix_length to usize
-    let split_index = (prefix_length + prefix_length / 8) as usize;
+// Split a binary string at the prefix length and colorize the network/host parts
+fn colorize_binary(binary_string_with_dots: &str, prefix_length: u32, group_bits: u32) -> String {
+    // Convert prefix_length to usize, accounting for the separators already inserted.
+    // At the maximum prefix length this overshoots by one (no separator follows the final
+    // group), so clamp to the string length rather than slicing out of bounds.
+    let split_index = ((prefix_length + prefix_length / group_bits) as usize).min(binary_string_with_dots.len());
 
     // Split the binary string at the prefix length
     let (network_part, host_part) = binary_string_with_dots.split_at(split_index);
@@ -160,14 +482,14 @@ fn to_colored_binary_string(addr: Ipv4Addr, prefix_length: u32) -> String {
     format!("{}{}", colored_network_part, colored_host_part)
 }
 
-// Add dots every 8 bits to the binary string
-fn add_dots(binary_string: &str) -> String {
+// Add a separator every `group_bits` bits to the binary string
+fn add_separators(binary_string: &str, group_bits: usize) -> String {
     binary_string
         .chars()
         .enumerate()
         .fold(String::new(), |mut acc, (i, c)| {
             acc.push(c);
-            if (i + 1) % 8 == 0 && i != binary_string.len() - 1 {
+            if (i + 1) % group_bits == 0 && i != binary_string.len() - 1 {
                 acc.push('.');
             }
             acc
@@ -195,4 +517,152 @@ fn get_network_type(ip: Ipv4Addr) -> &'static str {
         192 if octets[1] == 168 => "Private Internet",
         _ => "Public Internet",
     }
-}
\ No newline at end of file
+}
+
+// Determine the scope of a v6 address: link-local, unique local, multicast, or global
+fn get_v6_scope(ip: Ipv6Addr) -> &'static str {
+    let first_segment = ip.segments()[0];
+    if first_segment & 0xffc0 == 0xfe80 {
+        "Link-Local"
+    } else if first_segment & 0xfe00 == 0xfc00 {
+        "Unique Local"
+    } else if first_segment & 0xff00 == 0xff00 {
+        "Multicast"
+    } else {
+        "Global Unicast"
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_v6_cidr() {
+        let (network, mask) = parse_args(&[String::new(), "2001:db8::/32".to_string()]).unwrap();
+        assert_eq!(network, "2001:db8::".parse::<IpAddr>().unwrap());
+        assert_eq!(mask_to_prefix_length(mask), 32);
+    }
+
+    #[test]
+    fn v6_host_route_does_not_panic() {
+        // a /128 has no host part, previously this overran the separated binary string
+        let calc = compute_network("::1".parse().unwrap(), mask_from_prefix("::1".parse().unwrap(), 128));
+        assert_eq!(calc.network_address, calc.last_address);
+        let _ = to_colored_binary_string(calc.network_address, calc.prefix_length);
+    }
+
+    #[test]
+    fn v6_scope_detection() {
+        assert_eq!(get_v6_scope("fe80::1".parse().unwrap()), "Link-Local");
+        assert_eq!(get_v6_scope("fc00::1".parse().unwrap()), "Unique Local");
+        assert_eq!(get_v6_scope("ff02::1".parse().unwrap()), "Multicast");
+        assert_eq!(get_v6_scope("2001:db8::1".parse().unwrap()), "Global Unicast");
+    }
+
+    #[test]
+    fn splits_into_equal_children() {
+        let network: IpAddr = "192.168.0.0".parse().unwrap();
+        let mask = mask_from_prefix(network, 24);
+        let children = split_children(network, mask, 26).unwrap();
+        let addresses: Vec<String> = children.iter().map(|(n, _)| n.to_string()).collect();
+        assert_eq!(addresses, vec!["192.168.0.0", "192.168.0.64", "192.168.0.128", "192.168.0.192"]);
+        assert!(children.iter().all(|(_, m)| mask_to_prefix_length(*m) == 26));
+    }
+
+    #[test]
+    fn split_to_max_prefix_does_not_panic() {
+        let network: IpAddr = "10.0.0.0".parse().unwrap();
+        let mask = mask_from_prefix(network, 30);
+        let children = split_children(network, mask, 32).unwrap();
+        assert_eq!(children.len(), 4);
+        for (child_network, child_mask) in children {
+            let _ = compute_network(child_network, child_mask);
+        }
+    }
+
+    #[test]
+    fn split_rejects_shorter_or_equal_prefix() {
+        let network: IpAddr = "192.168.0.0".parse().unwrap();
+        let mask = mask_from_prefix(network, 24);
+        assert!(matches!(split_children(network, mask, 24), Err(NetworkParseError::InvalidMask)));
+        assert!(matches!(split_children(network, mask, 20), Err(NetworkParseError::InvalidMask)));
+    }
+
+    #[test]
+    fn accepts_contiguous_masks() {
+        assert!(is_contiguous_mask(u32::from("255.255.255.0".parse::<Ipv4Addr>().unwrap())));
+        assert!(is_contiguous_mask(0));
+        assert!(is_contiguous_mask(u32::MAX));
+    }
+
+    #[test]
+    fn rejects_non_contiguous_masks() {
+        assert!(!is_contiguous_mask(u32::from("255.0.255.0".parse::<Ipv4Addr>().unwrap())));
+    }
+
+    #[test]
+    fn parse_mask_rejects_non_contiguous_dotted_mask() {
+        let network: IpAddr = "192.168.0.0".parse().unwrap();
+        let result = parse_mask(network, "255.0.255.0");
+        assert!(matches!(result, Err(NetworkParseError::InvalidMask)));
+    }
+
+    #[test]
+    fn accepts_equivalent_mask_input_forms() {
+        let prefix_form = parse_args(&[String::new(), "192.0.2.16/29".to_string()]).unwrap();
+        let slash_dotted_form = parse_args(&[String::new(), "192.0.2.16/255.255.255.248".to_string()]).unwrap();
+        let space_separated_form =
+            parse_args(&["prog".to_string(), "192.0.2.16".to_string(), "255.255.255.248".to_string()]).unwrap();
+
+        assert_eq!(prefix_form, slash_dotted_form);
+        assert_eq!(prefix_form, space_separated_form);
+    }
+
+    #[test]
+    fn network_info_json_matches_table_values() {
+        let network: IpAddr = "192.168.1.0".parse().unwrap();
+        let mask = mask_from_prefix(network, 24);
+        let info = NetworkInfo::from(compute_network(network, mask));
+
+        assert_eq!(info.network, "192.168.1.0");
+        assert_eq!(info.prefix, 24);
+        assert_eq!(info.hostmin, "192.168.1.1");
+        assert_eq!(info.hostmax, "192.168.1.254");
+        assert_eq!(info.broadcast.as_deref(), Some("192.168.1.255"));
+        assert_eq!(info.class, Some('C'));
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["type"], "Private Internet");
+    }
+
+    #[test]
+    fn network_info_json_omits_v4_only_fields_for_v6() {
+        let network: IpAddr = "2001:db8::".parse().unwrap();
+        let mask = mask_from_prefix(network, 32);
+        let info = NetworkInfo::from(compute_network(network, mask));
+
+        assert_eq!(info.broadcast, None);
+        assert_eq!(info.class, None);
+        assert_eq!(info.network_type, "Global Unicast");
+    }
+
+    #[test]
+    fn aggregates_adjacent_networks_exactly() {
+        let networks = [parse_cidr("192.168.0.0/25").unwrap(), parse_cidr("192.168.0.128/25").unwrap()];
+        let (network, mask, is_exact) = aggregate_networks(&networks).unwrap();
+        assert_eq!(network.to_string(), "192.168.0.0");
+        assert_eq!(mask_to_prefix_length(mask), 24);
+        assert!(is_exact);
+    }
+
+    #[test]
+    fn aggregate_flags_gap_between_inputs_as_inexact() {
+        // only two of the four /24s that would make up a full /22 are given
+        let networks = [parse_cidr("10.0.0.0/24").unwrap(), parse_cidr("10.0.3.0/24").unwrap()];
+        let (network, mask, is_exact) = aggregate_networks(&networks).unwrap();
+        assert_eq!(network.to_string(), "10.0.0.0");
+        assert_eq!(mask_to_prefix_length(mask), 22);
+        assert!(!is_exact);
+    }
+}